@@ -1,4 +1,5 @@
 use fmt::Display;
+use std::cmp::Ordering;
 use std::collections::HashMap;
 use std::fmt;
 use std::fmt::Formatter;
@@ -7,10 +8,88 @@ use std::ops::Deref;
 use anyhow::{Error, Result};
 use sqlparser::ast;
 use sqlparser::ast::{Expr, OrderByExpr, Query, Select, SetExpr, Statement, TableFactor};
-use sqlparser::dialect::MySqlDialect;
-use sqlparser::parser::{Parser, ParserError};
+use sqlparser::dialect::{
+    Dialect as SqlParserDialect, GenericDialect, MySqlDialect, PostgreSqlDialect, SQLiteDialect,
+};
+use sqlparser::parser::Parser;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dialect {
+    MySql,
+    Postgres,
+    Generic,
+    SQLite,
+}
+
+impl Dialect {
+    fn as_sqlparser_dialect(&self) -> Box<dyn SqlParserDialect> {
+        match self {
+            Dialect::MySql => Box::new(MySqlDialect {}),
+            Dialect::Postgres => Box::new(PostgreSqlDialect {}),
+            Dialect::Generic => Box::new(GenericDialect {}),
+            Dialect::SQLite => Box::new(SQLiteDialect {}),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Span {
+    start: usize,
+    end: usize,
+}
 
-const DIALECT: MySqlDialect = MySqlDialect {};
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}..{}", self.start, self.end)
+    }
+}
+
+fn spans_overlap(a: &Span, b: &Span) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Resolves byte spans for extracted identifiers via textual search against
+/// the original SQL, rather than real `sqlparser` token locations — this is
+/// the fallback half only. A bare `str::find` always returns the *first*
+/// occurrence of a name, which is wrong the moment a name repeats (self-joins,
+/// a column name that also appears elsewhere in the statement, …), so this
+/// remembers which ranges it has already handed out and skips over them,
+/// resolving repeated identifiers to distinct, non-overlapping occurrences in
+/// the order they're claimed. Once every occurrence of a name has been
+/// claimed, a further lookup returns the first occurrence again, so that span
+/// overlaps an earlier one.
+struct SpanTracker<'a> {
+    sql: &'a str,
+    claimed: Vec<Span>,
+}
+
+impl<'a> SpanTracker<'a> {
+    fn new(sql: &'a str) -> Self {
+        SpanTracker { sql, claimed: Vec::new() }
+    }
+
+    fn locate(&mut self, needle: &str) -> Span {
+        if needle.is_empty() {
+            return Span::default();
+        }
+
+        let mut search_start = 0;
+        while let Some(offset) = self.sql[search_start..].find(needle) {
+            let start = search_start + offset;
+            let span = Span { start, end: start + needle.len() };
+            if !self.claimed.iter().any(|claimed| spans_overlap(claimed, &span)) {
+                self.claimed.push(span);
+                return span;
+            }
+            search_start = start + 1;
+        }
+
+        match self.sql.find(needle) {
+            Some(start) => Span { start, end: start + needle.len() },
+            None => Span::default(),
+        }
+    }
+}
 
 #[derive(Debug, Default)]
 struct TableInfo {
@@ -18,19 +97,109 @@ struct TableInfo {
     schema: String,
     table: String,
     alias: String,
+    span: Span,
 }
 
 impl Display for TableInfo {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         if self.schema.len() == 0 {
-            write!(f, "table{}: {}", self.index, self.table)
+            write!(f, "table{}: {} @{}", self.index, self.table, self.span)
         } else {
-            write!(f, "table{}: {}.{}", self.index, self.schema, self.table)
+            write!(f, "table{}: {}.{} @{}", self.index, self.schema, self.table, self.span)
         }
     }
 }
 
+fn extract_table_info(table_factor: &TableFactor, index: usize, tracker: &mut SpanTracker) -> Option<TableInfo> {
+    if let TableFactor::Table {
+        name: object_name,
+        alias: alias_option,
+        ..
+    } = table_factor {
+        let table_ident_vec = &object_name.0;
+        let table_ident_len = table_ident_vec.len();
+        let alias_name = alias_option.as_ref().map(|alias| alias.name.value.clone()).unwrap_or_default();
+
+        let mut table_info = TableInfo::default();
+        table_info.index = index;
+        table_info.alias = alias_name;
+        if table_ident_len == 2 {
+            table_info.schema = table_ident_vec[0].value.clone();
+            table_info.table = table_ident_vec[1].value.clone();
+        } else if table_ident_len == 1 {
+            table_info.table = table_ident_vec[0].value.clone();
+        } else {
+            return None;
+        }
+
+        table_info.span = tracker.locate(&table_info.table);
+        Some(table_info)
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum JoinKind {
+    Inner,
+    LeftOuter,
+    RightOuter,
+    Cross,
+}
+
+impl Display for JoinKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            JoinKind::Inner => "INNER",
+            JoinKind::LeftOuter => "LEFT OUTER",
+            JoinKind::RightOuter => "RIGHT OUTER",
+            JoinKind::Cross => "CROSS",
+        };
+        write!(f, "{}", label)
+    }
+}
+
 #[derive(Debug)]
+struct JoinInfo {
+    table: TableInfo,
+    kind: JoinKind,
+    on: Option<Predicate>,
+}
+
+impl Display for JoinInfo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.on {
+            Some(predicate) => write!(f, "{} JOIN {} ON {}", self.kind, self.table, predicate),
+            None => write!(f, "{} JOIN {}", self.kind, self.table),
+        }
+    }
+}
+
+fn build_join_constraint(constraint: &ast::JoinConstraint) -> Result<Option<Predicate>> {
+    match constraint {
+        ast::JoinConstraint::On(expr) => Ok(Some(build_predicate(expr)?)),
+        _ => Ok(None),
+    }
+}
+
+fn build_join_info(join: &ast::Join, index: usize, tracker: &mut SpanTracker) -> Result<Option<JoinInfo>> {
+    let table = match extract_table_info(&join.relation, index, tracker) {
+        Some(table) => table,
+        None => return Ok(None),
+    };
+
+    let (kind, on) = match &join.join_operator {
+        ast::JoinOperator::Inner(constraint) => (JoinKind::Inner, build_join_constraint(constraint)?),
+        ast::JoinOperator::LeftOuter(constraint) => (JoinKind::LeftOuter, build_join_constraint(constraint)?),
+        ast::JoinOperator::RightOuter(constraint) => (JoinKind::RightOuter, build_join_constraint(constraint)?),
+        ast::JoinOperator::CrossJoin => (JoinKind::Cross, None),
+        other => return Err(Error::msg(format!("unsupported join kind: {:?}", other))),
+    };
+
+    Ok(Some(JoinInfo { table, kind, on }))
+}
+
+#[derive(Debug, Clone)]
 enum MysqlValue {
     None,
     String(String),
@@ -38,15 +207,178 @@ enum MysqlValue {
     Boolean(bool),
 }
 
+impl PartialEq for MysqlValue {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(self.partial_cmp(other), Some(Ordering::Equal))
+    }
+}
+
+impl PartialOrd for MysqlValue {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (MysqlValue::None, MysqlValue::None) => Some(Ordering::Equal),
+            (MysqlValue::Number(a), MysqlValue::Number(b)) => a.partial_cmp(b),
+            (MysqlValue::String(a), MysqlValue::String(b)) => a.partial_cmp(b),
+            (MysqlValue::Boolean(a), MysqlValue::Boolean(b)) => a.partial_cmp(b),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CompareOp {
+    Eq,
+    NotEq,
+    Lt,
+    LtEq,
+    Gt,
+    GtEq,
+}
+
+impl Display for CompareOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            CompareOp::Eq => "=",
+            CompareOp::NotEq => "!=",
+            CompareOp::Lt => "<",
+            CompareOp::LtEq => "<=",
+            CompareOp::Gt => ">",
+            CompareOp::GtEq => ">=",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[derive(Debug)]
+enum Predicate {
+    And(Box<Predicate>, Box<Predicate>),
+    Or(Box<Predicate>, Box<Predicate>),
+    Not(Box<Predicate>),
+    Compare { field: String, op: CompareOp, value: MysqlValue },
+    IsNull { field: String, negated: bool },
+    InList { field: String, values: Vec<MysqlValue>, negated: bool },
+}
+
+impl Display for Predicate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Predicate::And(left, right) => write!(f, "({} AND {})", left, right),
+            Predicate::Or(left, right) => write!(f, "({} OR {})", left, right),
+            Predicate::Not(inner) => write!(f, "(NOT {})", inner),
+            Predicate::Compare { field, op, value } => write!(f, "{} {} {:?}", field, op, value),
+            Predicate::IsNull { field, negated } => {
+                if *negated {
+                    write!(f, "{} IS NOT NULL", field)
+                } else {
+                    write!(f, "{} IS NULL", field)
+                }
+            }
+            Predicate::InList { field, values, negated } => {
+                if *negated {
+                    write!(f, "{} NOT IN {:?}", field, values)
+                } else {
+                    write!(f, "{} IN {:?}", field, values)
+                }
+            }
+        }
+    }
+}
+
+fn expr_field_name(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Identifier(ident) => Some(ident.value.clone()),
+        Expr::CompoundIdentifier(idents) => idents.last().map(|ident| ident.value.clone()),
+        _ => None,
+    }
+}
+
+fn convert_value(value: &ast::Value) -> MysqlValue {
+    match value {
+        ast::Value::Number(num_str, _) => num_str
+            .parse::<u64>()
+            .map(MysqlValue::Number)
+            .unwrap_or(MysqlValue::None),
+        ast::Value::SingleQuotedString(str_val) => MysqlValue::String(str_val.clone()),
+        ast::Value::Boolean(b) => MysqlValue::Boolean(*b),
+        ast::Value::Null => MysqlValue::None,
+        _ => MysqlValue::None,
+    }
+}
+
+fn flip_compare_op(op: CompareOp) -> CompareOp {
+    match op {
+        CompareOp::Eq => CompareOp::Eq,
+        CompareOp::NotEq => CompareOp::NotEq,
+        CompareOp::Lt => CompareOp::Gt,
+        CompareOp::LtEq => CompareOp::GtEq,
+        CompareOp::Gt => CompareOp::Lt,
+        CompareOp::GtEq => CompareOp::LtEq,
+    }
+}
+
+fn build_compare(left: &Expr, right: &Expr, op: CompareOp) -> Result<Predicate> {
+    if let (Some(field), Expr::Value(value)) = (expr_field_name(left), right) {
+        return Ok(Predicate::Compare { field, op, value: convert_value(value) });
+    }
+
+    if let (Expr::Value(value), Some(field)) = (left, expr_field_name(right)) {
+        return Ok(Predicate::Compare { field, op: flip_compare_op(op), value: convert_value(value) });
+    }
+
+    Err(Error::msg(format!("unsupported comparison in WHERE clause: {} {} {}", left, op, right)))
+}
+
+fn build_predicate(expr: &Expr) -> Result<Predicate> {
+    match expr {
+        Expr::Nested(inner) => build_predicate(inner),
+        Expr::UnaryOp { op: ast::UnaryOperator::Not, expr: inner } => {
+            Ok(Predicate::Not(Box::new(build_predicate(inner)?)))
+        }
+        Expr::IsNull(inner) => {
+            let field = expr_field_name(inner).ok_or_else(|| Error::msg("IS NULL target is not a field"))?;
+            Ok(Predicate::IsNull { field, negated: false })
+        }
+        Expr::IsNotNull(inner) => {
+            let field = expr_field_name(inner).ok_or_else(|| Error::msg("IS NOT NULL target is not a field"))?;
+            Ok(Predicate::IsNull { field, negated: true })
+        }
+        Expr::InList { expr: inner, list, negated } => {
+            let field = expr_field_name(inner).ok_or_else(|| Error::msg("IN target is not a field"))?;
+            let values = list
+                .iter()
+                .map(|item| match item {
+                    Expr::Value(value) => Ok(convert_value(value)),
+                    other => Err(Error::msg(format!("IN list item is not a literal value: {}", other))),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(Predicate::InList { field, values, negated: *negated })
+        }
+        Expr::BinaryOp { left, op, right } => match op {
+            ast::BinaryOperator::And => Ok(Predicate::And(Box::new(build_predicate(left)?), Box::new(build_predicate(right)?))),
+            ast::BinaryOperator::Or => Ok(Predicate::Or(Box::new(build_predicate(left)?), Box::new(build_predicate(right)?))),
+            ast::BinaryOperator::Eq => build_compare(left, right, CompareOp::Eq),
+            ast::BinaryOperator::NotEq => build_compare(left, right, CompareOp::NotEq),
+            ast::BinaryOperator::Lt => build_compare(left, right, CompareOp::Lt),
+            ast::BinaryOperator::LtEq => build_compare(left, right, CompareOp::LtEq),
+            ast::BinaryOperator::Gt => build_compare(left, right, CompareOp::Gt),
+            ast::BinaryOperator::GtEq => build_compare(left, right, CompareOp::GtEq),
+            other => Err(Error::msg(format!("unsupported operator in WHERE clause: {}", other))),
+        },
+        other => Err(Error::msg(format!("unsupported expression in WHERE clause: {}", other))),
+    }
+}
+
 #[derive(Debug, Default)]
 struct SqlStruct {
     table_infos: Vec<TableInfo>,
-    get_fields: Vec<String>,
-    set_fields: HashMap<String, MysqlValue>,
-    order_by_fields: HashMap<String, bool>,
+    joins: Vec<JoinInfo>,
+    get_fields: Vec<(String, Span)>,
+    set_fields: HashMap<String, (MysqlValue, Span)>,
+    order_by_fields: Vec<(String, bool)>,
     limit: Option<i32>,
     offset: Option<i32>,
-    where_exist: bool,
+    where_clause: Option<Predicate>,
+    options: HashMap<String, MysqlValue>,
 }
 
 impl Display for SqlStruct {
@@ -65,10 +397,24 @@ impl Display for SqlStruct {
 
         table_info_str.push_str("]");
 
+        let mut joins_str = String::new();
+        joins_str.push_str("[");
+        for join_info in &self.joins {
+            joins_str.push_str(&join_info.to_string());
+            joins_str.push_str(", ");
+        }
+
+        if joins_str.len() > 1 {
+            joins_str.pop();
+            joins_str.pop();
+        }
+
+        joins_str.push_str("]");
+
         let mut select_fields_str = String::new();
         select_fields_str.push_str("[");
-        for select_field in &self.get_fields {
-            select_fields_str.push_str(select_field);
+        for (select_field, span) in &self.get_fields {
+            select_fields_str.push_str(&format!("{}@{}", select_field, span));
             select_fields_str.push_str(", ");
         }
 
@@ -81,10 +427,8 @@ impl Display for SqlStruct {
 
         let mut set_fields_str = String::new();
         set_fields_str.push_str("[");
-        for (k, v) in &self.set_fields {
-            set_fields_str.push_str(k);
-            set_fields_str.push_str("=");
-            set_fields_str.push_str(&*format!("{:?}", v));
+        for (k, (v, span)) in &self.set_fields {
+            set_fields_str.push_str(&format!("{}@{}={:?}", k, span, v));
             set_fields_str.push_str(", ");
         }
 
@@ -109,154 +453,161 @@ impl Display for SqlStruct {
         order_by_fields_str.pop();
         order_by_fields_str.pop();
 
+        let where_clause_str = match &self.where_clause {
+            Some(predicate) => predicate.to_string(),
+            None => "none".to_string(),
+        };
+
+        let mut options_str = String::new();
+        options_str.push_str("[");
+        for (k, v) in &self.options {
+            options_str.push_str(&format!("{}={:?}", k, v));
+            options_str.push_str(", ");
+        }
+
+        if options_str.len() > 1 {
+            options_str.pop();
+            options_str.pop();
+        }
+
+        options_str.push_str("]");
+
         write!(
             f,
-            "table_infos: {}, select_fields: {}, set_fields: {}, order_by_fields: {}, limit: {:?}, offset: {:?}, where_exist: {}",
-            table_info_str, select_fields_str, set_fields_str, order_by_fields_str, self.limit, self.offset, self.where_exist
+            "table_infos: {}, joins: {}, select_fields: {}, set_fields: {}, order_by_fields: {}, limit: {:?}, offset: {:?}, where_clause: {}, options: {}",
+            table_info_str, joins_str, select_fields_str, set_fields_str, order_by_fields_str, self.limit, self.offset, where_clause_str, options_str
         )
     }
 }
 
+fn parse(sql: &str, dialect: Dialect) -> Result<SqlStruct> {
+    let dialect_impl = dialect.as_sqlparser_dialect();
+    let statements = Parser::parse_sql(dialect_impl.as_ref(), sql).map_err(Error::msg)?;
+    let statement = statements.first().ok_or_else(|| Error::msg("no statement parsed"))?;
+
+    let mut tracker = SpanTracker::new(sql);
+
+    match statement {
+        Statement::Query(query) => parse_select(query, &mut tracker),
+        Statement::Insert { .. } => parse_insert(statement, &mut tracker),
+        Statement::Update { .. } => parse_update(statement, &mut tracker),
+        Statement::Delete { .. } => parse_delete(statement, &mut tracker),
+        Statement::CreateTable { .. } => parse_create(statement, &mut tracker),
+        Statement::Cache { .. } => parse_cache(statement, &mut tracker),
+        Statement::UNCache { .. } => parse_uncache(statement, &mut tracker),
+        other => Err(Error::msg(format!("unsupported statement: {:?}", other))),
+    }
+}
+
 /*
 解析SELECT语句
  */
-fn parse_select() -> Result<SqlStruct, Error> {
-    let sql = "SELECT ID, NAME, AGE FROM DB1.TB1 as t1, TB2 as t2 WHERE AGE > 20 ORDER BY AGE DESC, ID ASC LIMIT 10 OFFSET 2;";
-    let select_parse_result: Result<Vec<Statement>, ParserError> = Parser::parse_sql(&DIALECT, sql);
-    if select_parse_result.is_err() {
-        let err_msg = select_parse_result.err().unwrap();
-        return Err(Error::msg(err_msg));
-    }
-
-    let statements = select_parse_result.unwrap();
-    let statement = &statements[0];
-
+fn parse_select(query: &Query, tracker: &mut SpanTracker) -> Result<SqlStruct> {
     let mut sql_struct = SqlStruct::default();
 
-    if let Statement::Query(query) = statement {
-        let Query {
-            body,
-            order_by,
-            limit,
-            offset,
+    let Query {
+        body,
+        order_by,
+        limit,
+        offset,
+        ..
+    } = query;
+
+    if let SetExpr::Select(select) = body.deref() {
+        let Select {
+            projection: select_expr_vec,
+            from: table_expr_vec,
+            selection: where_expr,
             ..
-        } = query.deref();
+        } = select.deref();
 
-        if let SetExpr::Select(select) = body.deref() {
-            let Select {
-                projection: select_expr_vec,
-                from: table_expr_vec,
-                selection: where_expr,
-                ..
-            } = select.deref();
-
-            for select_item in select_expr_vec {
-                if let ast::SelectItem::UnnamedExpr(select_expr) = select_item {
-                    if let Expr::Identifier(select_field_ident) = select_expr {
-                        sql_struct.get_fields.push(select_field_ident.value.clone());
-                    }
-                } else if let ast::SelectItem::ExprWithAlias { expr: select_expr, .. } = select_item {
-                    if let Expr::Identifier(select_field_ident) = select_expr {
-                        sql_struct.get_fields.push(select_field_ident.value.clone());
-                    }
+        for select_item in select_expr_vec {
+            if let ast::SelectItem::UnnamedExpr(select_expr) = select_item {
+                if let Expr::Identifier(select_field_ident) = select_expr {
+                    let span = tracker.locate(&select_field_ident.value);
+                    sql_struct.get_fields.push((select_field_ident.value.clone(), span));
                 }
-            }
-
-            for (index, table_expr) in table_expr_vec.iter().enumerate() {
-                let ast::TableWithJoins {
-                    relation: table_factor,
-                    ..
-                } = table_expr.deref();
-
-                if let TableFactor::Table {
-                    name: object_name,
-                    alias: alias_option,
-                    ..
-                } = table_factor {
-                    let table_ident_vec = &object_name.0;
-                    let table_ident_len = table_ident_vec.len();
-                    let alias_name = {
-                        if let Some(alias) = alias_option {
-                            &alias.name.value
-                        } else {
-                            ""
-                        }
-                    };
-
-                    let mut table_info = TableInfo::default();
-                    table_info.index = index + 1;
-                    table_info.alias = alias_name.to_string();
-                    if table_ident_len == 2 {
-                        table_info.schema = table_ident_vec[0].value.clone();
-                        table_info.table = table_ident_vec[1].value.clone();
-                        sql_struct.table_infos.push(table_info);
-                    } else if table_ident_len == 1 {
-                        table_info.table = table_ident_vec[0].value.clone();
-                        sql_struct.table_infos.push(table_info);
-                    }
+            } else if let ast::SelectItem::ExprWithAlias { expr: select_expr, .. } = select_item {
+                if let Expr::Identifier(select_field_ident) = select_expr {
+                    let span = tracker.locate(&select_field_ident.value);
+                    sql_struct.get_fields.push((select_field_ident.value.clone(), span));
                 }
             }
-
-            sql_struct.where_exist = where_expr.is_some();
         }
 
-        if let Some(limit_expr) = limit {
-            if let Expr::Value(limit_value) = limit_expr {
-                if let ast::Value::Number(limit_number, ..) = limit_value {
-                    sql_struct.limit = Some(limit_number.parse::<i32>().unwrap());
+        let mut next_index = 1;
+        for table_expr in table_expr_vec.iter() {
+            let ast::TableWithJoins {
+                relation: table_factor,
+                joins,
+            } = table_expr.deref();
+
+            if let Some(table_info) = extract_table_info(table_factor, next_index, tracker) {
+                sql_struct.table_infos.push(table_info);
+            }
+            next_index += 1;
+
+            for join in joins {
+                if let Some(join_info) = build_join_info(join, next_index, tracker)? {
+                    sql_struct.joins.push(join_info);
                 }
+                next_index += 1;
             }
         }
 
-        if let Some(offset_struct) = offset {
-            let offset_value = &offset_struct.value;
-            if let Expr::Value(offset_value) = offset_value {
-                if let ast::Value::Number(offset_number, ..) = offset_value {
-                    sql_struct.offset = Some(offset_number.parse::<i32>().unwrap());
-                }
+        sql_struct.where_clause = where_expr.as_ref().map(build_predicate).transpose()?;
+    }
+
+    if let Some(limit_expr) = limit {
+        if let Expr::Value(limit_value) = limit_expr {
+            if let ast::Value::Number(limit_number, ..) = limit_value {
+                sql_struct.limit = Some(limit_number.parse::<i32>().unwrap());
             }
         }
+    }
 
-        let mut order_by_fields: HashMap<String, bool> = HashMap::with_capacity(order_by.len());
-        for order_item in order_by {
-            let OrderByExpr { expr: order_by_expr, asc, .. } = order_item;
-            if let Expr::Identifier(order_by_field_ident) = order_by_expr {
-                let order_value = asc.unwrap_or(false);
-                order_by_fields.insert(order_by_field_ident.value.clone(), order_value);
+    if let Some(offset_struct) = offset {
+        let offset_value = &offset_struct.value;
+        if let Expr::Value(offset_value) = offset_value {
+            if let ast::Value::Number(offset_number, ..) = offset_value {
+                sql_struct.offset = Some(offset_number.parse::<i32>().unwrap());
             }
         }
+    }
 
-        sql_struct.order_by_fields = order_by_fields;
+    let mut order_by_fields: Vec<(String, bool)> = Vec::with_capacity(order_by.len());
+    for order_item in order_by {
+        let OrderByExpr { expr: order_by_expr, asc, .. } = order_item;
+        if let Expr::Identifier(order_by_field_ident) = order_by_expr {
+            let order_value = asc.unwrap_or(true);
+            order_by_fields.push((order_by_field_ident.value.clone(), order_value));
+        }
     }
 
+    sql_struct.order_by_fields = order_by_fields;
+
     Ok(sql_struct)
 }
 
-fn parse_insert() -> Result<SqlStruct, Error> {
-    let sql = "INSERT INTO a.TB1 (NAME,AGE,FLAG) VALUES('ZHANG_SAN', 20, true);";
-    let select_parse_result: Result<Vec<Statement>, ParserError> = Parser::parse_sql(&DIALECT, sql);
-    if select_parse_result.is_err() {
-        let err_msg = select_parse_result.err().unwrap();
-        return Err(Error::msg(err_msg));
-    }
-
-    let statements = select_parse_result.unwrap();
-    let statement = &statements[0];
-
+fn parse_insert(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
     let mut sql_struct = SqlStruct::default();
 
     if let Statement::Insert { table_name: table_name_vec, columns, source: query, .. } = statement {
         let table_ident_vec = &table_name_vec.0;
         let table_ident_len = table_ident_vec.len();
         if table_ident_len == 2 {
+            let span = tracker.locate(&table_ident_vec[1].value);
             sql_struct.table_infos.push(TableInfo {
                 schema: table_ident_vec[0].value.clone(),
                 table: table_ident_vec[1].value.clone(),
+                span,
                 ..Default::default()
             });
         } else if table_ident_len == 1 {
+            let span = tracker.locate(&table_ident_vec[0].value);
             sql_struct.table_infos.push(TableInfo {
                 table: table_ident_vec[0].value.clone(),
+                span,
                 ..Default::default()
             });
         }
@@ -266,7 +617,8 @@ fn parse_insert() -> Result<SqlStruct, Error> {
         sql_struct.set_fields = HashMap::new();
 
         for column in columns {
-            sql_struct.set_fields.insert(column.value.clone(), MysqlValue::None);
+            let span = tracker.locate(&column.value);
+            sql_struct.set_fields.insert(column.value.clone(), (MysqlValue::None, span));
             insert_field_name_vec.push(column.value.clone());
         }
 
@@ -284,16 +636,19 @@ fn parse_insert() -> Result<SqlStruct, Error> {
                         if let Expr::Value(value) = item {
                             if let ast::Value::Number(num_str, _) = value {
                                 let field_name = insert_field_name_vec.get(insert_field_index).unwrap();
-                                sql_struct.set_fields.insert(field_name.clone(), MysqlValue::Number(num_str.parse::<u64>().unwrap()));
+                                let span = tracker.locate(field_name);
+                                sql_struct.set_fields.insert(field_name.clone(), (MysqlValue::Number(num_str.parse::<u64>().unwrap()), span));
                                 insert_field_index = insert_field_index + 1;
                             } else if let ast::Value::SingleQuotedString(str_val) = value {
                                 let field_name = insert_field_name_vec.get(insert_field_index).unwrap();
+                                let span = tracker.locate(field_name);
                                 let s = str_val.clone();
-                                sql_struct.set_fields.insert(field_name.clone(), MysqlValue::String(s));
+                                sql_struct.set_fields.insert(field_name.clone(), (MysqlValue::String(s), span));
                                 insert_field_index = insert_field_index + 1;
                             } else if let ast::Value::Boolean(b) = value {
                                 let field_name = insert_field_name_vec.get(insert_field_index).unwrap();
-                                sql_struct.set_fields.insert(field_name.clone(), MysqlValue::Boolean(*b));
+                                let span = tracker.locate(field_name);
+                                sql_struct.set_fields.insert(field_name.clone(), (MysqlValue::Boolean(*b), span));
                                 insert_field_index = insert_field_index + 1;
                             }
                         }
@@ -307,17 +662,7 @@ fn parse_insert() -> Result<SqlStruct, Error> {
     Ok(sql_struct)
 }
 
-fn parse_update() -> Result<SqlStruct, Error> {
-    let sql = "UPDATE TB1 SET NAME = 'name1', FLAG = false WHERE AGE > 10;";
-    let update_parse_result: Result<Vec<Statement>, ParserError> = Parser::parse_sql(&DIALECT, sql);
-    if update_parse_result.is_err() {
-        let err_msg = update_parse_result.err().unwrap();
-        return Err(Error::msg(err_msg));
-    }
-
-    let statements = update_parse_result.unwrap();
-    let statement = &statements[0];
-
+fn parse_update(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
     let mut sql_struct = SqlStruct::default();
     if let Statement::Update {
         table,
@@ -330,14 +675,18 @@ fn parse_update() -> Result<SqlStruct, Error> {
             let table_ident_vec = &table_name_vec.0;
             let table_ident_len = table_ident_vec.len();
             if table_ident_len == 2 {
+                let span = tracker.locate(&table_ident_vec[1].value);
                 sql_struct.table_infos.push(TableInfo {
                     schema: table_ident_vec[0].value.clone(),
                     table: table_ident_vec[1].value.clone(),
+                    span,
                     ..Default::default()
                 });
             } else if table_ident_len == 1 {
+                let span = tracker.locate(&table_ident_vec[0].value);
                 sql_struct.table_infos.push(TableInfo {
                     table: table_ident_vec[0].value.clone(),
+                    span,
                     ..Default::default()
                 });
             }
@@ -348,105 +697,75 @@ fn parse_update() -> Result<SqlStruct, Error> {
         for assignment in assignments {
             let key: &String = &assignment.id[0].value;
             let value = &assignment.value;
+            let span = tracker.locate(key);
 
             if let Expr::Value(value) = value {
                 if let ast::Value::Number(num_str, _) = value {
-                    sql_struct.set_fields.insert(key.clone(), MysqlValue::Number(num_str.parse::<u64>().unwrap()));
+                    sql_struct.set_fields.insert(key.clone(), (MysqlValue::Number(num_str.parse::<u64>().unwrap()), span));
                 } else if let ast::Value::SingleQuotedString(str_val) = value {
                     let s = str_val.clone();
-                    sql_struct.set_fields.insert(key.clone(), MysqlValue::String(s));
+                    sql_struct.set_fields.insert(key.clone(), (MysqlValue::String(s), span));
                 } else if let ast::Value::Boolean(b) = value {
-                    sql_struct.set_fields.insert(key.clone(), MysqlValue::Boolean(*b));
+                    sql_struct.set_fields.insert(key.clone(), (MysqlValue::Boolean(*b), span));
                 }
             }
         }
 
-        sql_struct.where_exist = where_expr.is_some();
+        sql_struct.where_clause = where_expr.as_ref().map(build_predicate).transpose()?;
     };
 
     Ok(sql_struct)
 }
 
-fn parse_delete() -> Result<SqlStruct, Error> {
-    let sql = "DELETE FROM TB1 WHERE AGE > 10;";
-    let select_parse_result: Result<Vec<Statement>, ParserError> = Parser::parse_sql(&DIALECT, sql);
-    if select_parse_result.is_err() {
-        let err_msg = select_parse_result.err().unwrap();
-        return Err(Error::msg(err_msg));
-    }
-
-    let statements = select_parse_result.unwrap();
-    let statement = &statements[0];
-
+fn parse_delete(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
     let mut sql_struct = SqlStruct::default();
 
     if let Statement::Delete { from: table_expr_vec, selection: where_expr, .. } = statement {
-        for (index, table_expr) in table_expr_vec.iter().enumerate() {
+        let mut next_index = 1;
+        for table_expr in table_expr_vec.iter() {
             let ast::TableWithJoins {
                 relation: table_factor,
-                ..
+                joins,
             } = table_expr.deref();
 
-            if let TableFactor::Table {
-                name: object_name,
-                alias: alias_option,
-                ..
-            } = table_factor {
-                let table_ident_vec = &object_name.0;
-                let table_ident_len = table_ident_vec.len();
-                let alias_name = {
-                    if let Some(alias) = alias_option {
-                        &alias.name.value
-                    } else {
-                        ""
-                    }
-                };
+            if let Some(table_info) = extract_table_info(table_factor, next_index, tracker) {
+                sql_struct.table_infos.push(table_info);
+            }
+            next_index += 1;
 
-                let mut table_info = TableInfo::default();
-                table_info.index = index + 1;
-                table_info.alias = alias_name.to_string();
-                if table_ident_len == 2 {
-                    table_info.schema = table_ident_vec[0].value.clone();
-                    table_info.table = table_ident_vec[1].value.clone();
-                    sql_struct.table_infos.push(table_info);
-                } else if table_ident_len == 1 {
-                    table_info.table = table_ident_vec[0].value.clone();
-                    sql_struct.table_infos.push(table_info);
+            for join in joins {
+                if let Some(join_info) = build_join_info(join, next_index, tracker)? {
+                    sql_struct.joins.push(join_info);
                 }
+                next_index += 1;
             }
         }
 
-        sql_struct.where_exist = where_expr.is_some();
+        sql_struct.where_clause = where_expr.as_ref().map(build_predicate).transpose()?;
     }
 
     Ok(sql_struct)
 }
 
-fn parse_create() -> Result<SqlStruct, Error> {
-    let sql = "CREATE TABLE TB1 (ID INT PRIMARY KEY AUTO_INCREMENT, NAME VARCHAR(20) NOT NULL COMMENT '姓名', AGE INT, FLAG BOOLEAN);";
-    let create_parse_result: Result<Vec<Statement>, ParserError> = Parser::parse_sql(&DIALECT, sql);
-    if create_parse_result.is_err() {
-        let err_msg = create_parse_result.err().unwrap();
-        return Err(Error::msg(err_msg));
-    }
-
-    let statements = create_parse_result.unwrap();
-    let statement = &statements[0];
-
+fn parse_create(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
     let mut sql_struct = SqlStruct::default();
 
     if let Statement::CreateTable { name: table_name_vec, columns, .. } = statement {
         let table_ident_vec = &table_name_vec.0;
         let table_ident_len = table_ident_vec.len();
         if table_ident_len == 2 {
+            let span = tracker.locate(&table_ident_vec[1].value);
             sql_struct.table_infos.push(TableInfo {
                 schema: table_ident_vec[0].value.clone(),
                 table: table_ident_vec[1].value.clone(),
+                span,
                 ..Default::default()
             });
         } else if table_ident_len == 1 {
+            let span = tracker.locate(&table_ident_vec[0].value);
             sql_struct.table_infos.push(TableInfo {
                 table: table_ident_vec[0].value.clone(),
+                span,
                 ..Default::default()
             });
         }
@@ -454,27 +773,220 @@ fn parse_create() -> Result<SqlStruct, Error> {
         sql_struct.set_fields = HashMap::new();
 
         for column in columns {
-            sql_struct.set_fields.insert(column.name.value.clone(), MysqlValue::None);
+            let span = tracker.locate(&column.name.value);
+            sql_struct.set_fields.insert(column.name.value.clone(), (MysqlValue::None, span));
+        }
+    }
+
+    Ok(sql_struct)
+}
+
+fn table_info_from_object_name(table_name: &ast::ObjectName, tracker: &mut SpanTracker) -> Option<TableInfo> {
+    let table_ident_vec = &table_name.0;
+    let table_ident_len = table_ident_vec.len();
+    if table_ident_len == 2 {
+        let span = tracker.locate(&table_ident_vec[1].value);
+        Some(TableInfo {
+            schema: table_ident_vec[0].value.clone(),
+            table: table_ident_vec[1].value.clone(),
+            span,
+            ..Default::default()
+        })
+    } else if table_ident_len == 1 {
+        let span = tracker.locate(&table_ident_vec[0].value);
+        Some(TableInfo {
+            table: table_ident_vec[0].value.clone(),
+            span,
+            ..Default::default()
+        })
+    } else {
+        None
+    }
+}
+
+fn parse_cache(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
+    let mut sql_struct = SqlStruct::default();
+
+    if let Statement::Cache { table_name: table_name_vec, options, query, .. } = statement {
+        if let Some(table_info) = table_info_from_object_name(table_name_vec, tracker) {
+            sql_struct.table_infos.push(table_info);
+        }
+
+        for option in options {
+            let value = convert_value(&option.value);
+            sql_struct.options.insert(option.name.value.clone(), value);
+        }
+
+        if let Some(cached_query) = query {
+            let cached_select = parse_select(cached_query, tracker)?;
+            sql_struct.get_fields = cached_select.get_fields;
+        }
+    }
+
+    Ok(sql_struct)
+}
+
+fn parse_uncache(statement: &Statement, tracker: &mut SpanTracker) -> Result<SqlStruct> {
+    let mut sql_struct = SqlStruct::default();
+
+    if let Statement::UNCache { table_name: table_name_vec, .. } = statement {
+        if let Some(table_info) = table_info_from_object_name(table_name_vec, tracker) {
+            sql_struct.table_infos.push(table_info);
         }
     }
 
     Ok(sql_struct)
 }
 
+fn eval_predicate(predicate: &Predicate, row: &HashMap<String, MysqlValue>) -> bool {
+    match predicate {
+        Predicate::And(left, right) => eval_predicate(left, row) && eval_predicate(right, row),
+        Predicate::Or(left, right) => eval_predicate(left, row) || eval_predicate(right, row),
+        Predicate::Not(inner) => !eval_predicate(inner, row),
+        Predicate::Compare { field, op, value } => {
+            let Some(field_value) = row.get(field) else { return false; };
+            let Some(ordering) = field_value.partial_cmp(value) else { return false; };
+            match op {
+                CompareOp::Eq => ordering == Ordering::Equal,
+                CompareOp::NotEq => ordering != Ordering::Equal,
+                CompareOp::Lt => ordering == Ordering::Less,
+                CompareOp::LtEq => ordering != Ordering::Greater,
+                CompareOp::Gt => ordering == Ordering::Greater,
+                CompareOp::GtEq => ordering != Ordering::Less,
+            }
+        }
+        Predicate::IsNull { field, negated } => {
+            let is_null = row.get(field).map_or(true, |value| matches!(value, MysqlValue::None));
+            is_null != *negated
+        }
+        Predicate::InList { field, values, negated } => {
+            let Some(field_value) = row.get(field) else { return false; };
+            let contains = values.iter().any(|value| field_value == value);
+            contains != *negated
+        }
+    }
+}
+
+fn execute(sql_struct: &SqlStruct, rows: Vec<HashMap<String, MysqlValue>>) -> Result<Vec<HashMap<String, MysqlValue>>> {
+    let mut rows: Vec<HashMap<String, MysqlValue>> = rows
+        .into_iter()
+        .filter(|row| {
+            sql_struct
+                .where_clause
+                .as_ref()
+                .map_or(true, |predicate| eval_predicate(predicate, row))
+        })
+        .collect();
+
+    if !sql_struct.order_by_fields.is_empty() {
+        rows.sort_by(|a, b| {
+            for (field, ascending) in &sql_struct.order_by_fields {
+                let ordering = match (a.get(field), b.get(field)) {
+                    (Some(value_a), Some(value_b)) => value_a.partial_cmp(value_b).unwrap_or(Ordering::Equal),
+                    _ => Ordering::Equal,
+                };
+                let ordering = if *ascending { ordering } else { ordering.reverse() };
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            Ordering::Equal
+        });
+    }
+
+    if let Some(offset) = sql_struct.offset {
+        if offset > 0 {
+            rows = rows.into_iter().skip(offset as usize).collect();
+        }
+    }
+
+    if let Some(limit) = sql_struct.limit {
+        if limit >= 0 {
+            rows.truncate(limit as usize);
+        }
+    }
+
+    let rows = rows
+        .into_iter()
+        .map(|row| {
+            if sql_struct.get_fields.is_empty() {
+                row
+            } else {
+                sql_struct
+                    .get_fields
+                    .iter()
+                    .filter_map(|(field, _span)| row.get(field).map(|value| (field.clone(), value.clone())))
+                    .collect()
+            }
+        })
+        .collect();
+
+    Ok(rows)
+}
+
 fn main() {
-    let mut parser_func_map: HashMap<String, fn() -> Result<SqlStruct>> = HashMap::new();
-    parser_func_map.insert("SELECT".to_string(), parse_select);
-    parser_func_map.insert("INSERT".to_string(), parse_insert);
-    parser_func_map.insert("UPDATE".to_string(), parse_update);
-    parser_func_map.insert("DELETE".to_string(), parse_delete);
-    parser_func_map.insert("CREATE".to_string(), parse_create);
-
-    for (parser_type, parser_func) in parser_func_map {
-        let parser_result = parser_func();
+    let mut demo_sqls: HashMap<String, (&str, Dialect)> = HashMap::new();
+    demo_sqls.insert(
+        "SELECT".to_string(),
+        ("SELECT ID, NAME, AGE FROM DB1.TB1 as t1, TB2 as t2 WHERE AGE > 20 ORDER BY AGE DESC, ID ASC LIMIT 10 OFFSET 2;", Dialect::MySql),
+    );
+    demo_sqls.insert(
+        "INSERT".to_string(),
+        ("INSERT INTO a.TB1 (NAME,AGE,FLAG) VALUES('ZHANG_SAN', 20, true);", Dialect::MySql),
+    );
+    demo_sqls.insert(
+        "UPDATE".to_string(),
+        ("UPDATE TB1 SET NAME = 'name1', FLAG = false WHERE AGE > 10;", Dialect::MySql),
+    );
+    demo_sqls.insert(
+        "DELETE".to_string(),
+        ("DELETE FROM TB1 WHERE AGE > 10;", Dialect::MySql),
+    );
+    demo_sqls.insert(
+        "CREATE".to_string(),
+        ("CREATE TABLE TB1 (ID INT PRIMARY KEY AUTO_INCREMENT, NAME VARCHAR(20) NOT NULL COMMENT '姓名', AGE INT, FLAG BOOLEAN);", Dialect::MySql),
+    );
+    demo_sqls.insert(
+        "CACHE".to_string(),
+        ("CACHE TABLE TB1 OPTIONS('storageLevel' = 'MEMORY_ONLY') AS SELECT ID, NAME FROM TB1;", Dialect::Generic),
+    );
+    demo_sqls.insert(
+        "UNCACHE".to_string(),
+        ("UNCACHE TABLE IF EXISTS TB1;", Dialect::Generic),
+    );
+
+    for (sql_type, (sql, dialect)) in demo_sqls {
+        let parser_result = parse(sql, dialect);
         if let Some(parser_struct) = parser_result.as_ref().ok() {
-            println!("{}解析结果：{}\n", parser_type, parser_struct);
+            println!("{}解析结果：{}\n", sql_type, parser_struct);
         } else {
-            println!("{}解析失败：{:?}\n", parser_type, parser_result.err().unwrap());
+            println!("{}解析失败：{:?}\n", sql_type, parser_result.err().unwrap());
+        }
+    }
+
+    let select_sql = "SELECT ID, NAME, AGE FROM TB1 WHERE AGE > 18 ORDER BY AGE DESC LIMIT 2;";
+    if let Ok(select_struct) = parse(select_sql, Dialect::MySql) {
+        let rows = vec![
+            HashMap::from([
+                ("ID".to_string(), MysqlValue::Number(1)),
+                ("NAME".to_string(), MysqlValue::String("ZHANG_SAN".to_string())),
+                ("AGE".to_string(), MysqlValue::Number(20)),
+            ]),
+            HashMap::from([
+                ("ID".to_string(), MysqlValue::Number(2)),
+                ("NAME".to_string(), MysqlValue::String("LI_SI".to_string())),
+                ("AGE".to_string(), MysqlValue::Number(17)),
+            ]),
+            HashMap::from([
+                ("ID".to_string(), MysqlValue::Number(3)),
+                ("NAME".to_string(), MysqlValue::String("WANG_WU".to_string())),
+                ("AGE".to_string(), MysqlValue::Number(35)),
+            ]),
+        ];
+
+        match execute(&select_struct, rows) {
+            Ok(result_rows) => println!("execute结果：{:?}\n", result_rows),
+            Err(e) => println!("execute失败：{:?}\n", e),
         }
     }
 }